@@ -1,40 +1,507 @@
 use anyhow::Error;
 use collectd_plugin::{
-    collectd_plugin, CollectdLoggerBuilder, ConfigItem, LogLevel, Plugin, PluginCapabilities,
-    PluginManager, PluginManagerCapabilities, PluginRegistration, ValueList, ValueReport,
+    collectd_plugin, CdTime, CollectdLoggerBuilder, ConfigItem, ConfigValue, LogLevel, Plugin,
+    PluginCapabilities, PluginManager, PluginManagerCapabilities, PluginRegistration, Value,
+    ValueList, ValueReport,
 };
+use env_logger::filter::{Builder as FilterBuilder, Filter};
 use log::LevelFilter;
 use once_cell::sync::Lazy;
 use rayon::prelude::*;
+use regex::Regex;
 use rillrate::protocol::pathfinder::{Pathfinder, Record};
 use rillrate::protocol::provider::{EntryId, Path};
-use rillrate::rill::prelude::LogTracer;
+use rillrate::rill::prelude::{CounterTracer, GaugeTracer, LogTracer};
 use rillrate::RillRate;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::convert::TryFrom;
 use std::error;
-use std::sync::{Mutex, RwLock};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::{Duration, SystemTime};
 use strum::IntoEnumIterator;
 
+/// Default for how long a log record stays in the [`LogBuffer`] before it's swept
+/// away; the `LogRetention` config key overrides it.
+const LOG_RETENTION: Duration = Duration::from_secs(86_400);
+/// How often the background sweeper wakes up to evict expired records.
+const LOG_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+/// Hard cap on [`LogBuffer`] entries, enforced on every push regardless of the
+/// time-based sweep, so the buffer is bounded even under a chatty log source.
+const LOG_BUFFER_CAPACITY: usize = 10_000;
+/// Default cap on the number of records a [`LogQuery`] returns.
+const DEFAULT_QUERY_LIMIT: usize = 100;
+
 static RILLRATE: Lazy<Mutex<Option<RillRate>>> = Lazy::new(|| Mutex::new(None));
+static SETTINGS: Lazy<Mutex<Settings>> = Lazy::new(|| Mutex::new(Settings::default()));
+
+/// Settings parsed out of the `<Plugin "teleport-collectd"> ... </Plugin>` config block.
+#[derive(Debug, Default, Clone)]
+struct Settings {
+    /// RillRate node/bind address, e.g. `ws://0.0.0.0:9090`. Falls back to `RillRate::from_env`.
+    node: Option<String>,
+    /// Overrides the host name RillRate reports entries under.
+    hostname: Option<String>,
+    /// env_logger-style filter directive string, e.g. `warn` or `debug`. `Plugin::log`
+    /// only sees a level and a message (collectd doesn't pass through the originating
+    /// Rust module), so only the directive's base level is honored here; per-target
+    /// components are accepted but have no effect on what reaches RillRate.
+    log_level: Option<String>,
+    /// If set, only these collectd plugin names are forwarded.
+    allow: Option<HashSet<String>>,
+    /// Collectd plugin names that are never forwarded, even if allow-listed.
+    deny: HashSet<String>,
+    /// Suppress forwarding a sample that hashes the same as the last one sent for its path.
+    dedup: bool,
+    /// How long, in seconds, a record stays in the in-memory log buffer. Defaults to
+    /// [`LOG_RETENTION`] when unset.
+    log_retention_secs: Option<u64>,
+}
+
+impl Settings {
+    fn parse(config: Option<&[ConfigItem<'_>]>) -> Self {
+        let mut settings = Settings::default();
+        for item in config.into_iter().flatten() {
+            match item.key.to_lowercase().as_str() {
+                "node" => settings.node = first_str(item).map(str::to_string),
+                "hostname" => settings.hostname = first_str(item).map(str::to_string),
+                "loglevel" => settings.log_level = first_str(item).map(str::to_string),
+                "plugins" => settings.allow = Some(all_str(item)),
+                "excludeplugins" => settings.deny = all_str(item),
+                "dedup" => {
+                    settings.dedup = item
+                        .values
+                        .iter()
+                        .find_map(config_value_as_bool)
+                        .unwrap_or(false)
+                }
+                "logretention" => {
+                    settings.log_retention_secs = item
+                        .values
+                        .iter()
+                        .find_map(config_value_as_number)
+                        .map(|n| n as u64)
+                }
+                other => log::warn!("Unknown teleport-collectd config key: {}", other),
+            }
+        }
+        settings
+    }
+
+    fn is_allowed(&self, plugin: &str) -> bool {
+        if self.deny.contains(plugin) {
+            return false;
+        }
+        match &self.allow {
+            Some(allow) => allow.contains(plugin),
+            None => true,
+        }
+    }
+}
+
+fn first_str<'a>(item: &'a ConfigItem<'_>) -> Option<&'a str> {
+    item.values.iter().find_map(config_value_as_str)
+}
+
+fn all_str(item: &ConfigItem<'_>) -> HashSet<String> {
+    item.values
+        .iter()
+        .filter_map(config_value_as_str)
+        .map(str::to_string)
+        .collect()
+}
+
+fn config_value_as_str<'a>(value: &'a ConfigValue<'_>) -> Option<&'a str> {
+    match value {
+        ConfigValue::String(s) => Some(s),
+        _ => None,
+    }
+}
+
+fn config_value_as_bool(value: &ConfigValue<'_>) -> Option<bool> {
+    match value {
+        ConfigValue::Boolean(b) => Some(*b),
+        _ => None,
+    }
+}
+
+fn config_value_as_number(value: &ConfigValue<'_>) -> Option<f64> {
+    match value {
+        ConfigValue::Number(n) => Some(*n),
+        _ => None,
+    }
+}
+
+/// A single log line captured while it passed through `Plugin::log`, or an event
+/// the plugin recorded itself (e.g. creating or replacing a tracer in `write_value`).
+struct LogRecord {
+    level: LogLevel,
+    target: String,
+    message: String,
+    timestamp: SystemTime,
+}
+
+/// Predicates used to filter a [`LogBuffer::query`] result.
+#[derive(Default)]
+struct LogQuery {
+    min_level: Option<LogLevel>,
+    target: Option<String>,
+    message: Option<Regex>,
+    not_before: Option<SystemTime>,
+    limit: Option<usize>,
+}
+
+impl LogQuery {
+    fn matches(&self, record: &LogRecord) -> bool {
+        if let Some(min_level) = &self.min_level {
+            if severity(&record.level) > severity(min_level) {
+                return false;
+            }
+        }
+        if let Some(target) = &self.target {
+            if !record.target.contains(target.as_str()) {
+                return false;
+            }
+        }
+        if let Some(message) = &self.message {
+            if !message.is_match(&record.message) {
+                return false;
+            }
+        }
+        if let Some(not_before) = self.not_before {
+            if record.timestamp < not_before {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn severity(level: &LogLevel) -> u8 {
+    match level {
+        LogLevel::Error => 0,
+        LogLevel::Warning => 1,
+        LogLevel::Notice => 2,
+        LogLevel::Info => 3,
+        LogLevel::Debug => 4,
+    }
+}
+
+fn to_log_level(level: &LogLevel) -> log::Level {
+    match level {
+        LogLevel::Error => log::Level::Error,
+        LogLevel::Warning => log::Level::Warn,
+        LogLevel::Notice => log::Level::Info,
+        LogLevel::Info => log::Level::Info,
+        LogLevel::Debug => log::Level::Debug,
+    }
+}
+
+/// Compiles the `LogLevel`/`COLLECTD_LOG`/`RUST_LOG` filter directive into an
+/// `env_logger` filter so `Plugin::log` can suppress entries before they reach
+/// RillRate. Every record built from a `Plugin::log` callback carries the same
+/// fixed target (`Self::name()`), since collectd doesn't pass the originating
+/// Rust module through its log callback — so only the directive's base level
+/// is actually enforceable here, not per-module directives.
+fn build_filter(settings: &Settings) -> Filter {
+    let directives = settings
+        .log_level
+        .clone()
+        .or_else(|| std::env::var("COLLECTD_LOG").ok())
+        .or_else(|| std::env::var("RUST_LOG").ok());
+    let mut builder = FilterBuilder::new();
+    match directives {
+        Some(directives) => {
+            warn_on_unsupported_directives(&directives);
+            builder.parse(&directives);
+        }
+        None => {
+            builder.filter_level(LevelFilter::Info);
+        }
+    }
+    builder.build()
+}
+
+/// `Plugin::log` builds every record with the fixed target `Self::name()`, so a
+/// module-scoped directive (e.g. `teleport_collectd::write=debug`) can never match
+/// and only the directive's bare level is actually enforced. Warn loudly instead of
+/// silently half-applying it, so operators don't assume per-module scoping works.
+fn warn_on_unsupported_directives(directives: &str) {
+    for directive in directives.split(',') {
+        let directive = directive.trim();
+        if !directive.is_empty() && !is_bare_level(directive) {
+            log::warn!(
+                "teleport-collectd: LogLevel directive {:?} targets a Rust module, but \
+                 collectd's log callback doesn't carry the originating module through to \
+                 RillRate -- only its base level will be honored",
+                directive
+            );
+        }
+    }
+}
+
+fn is_bare_level(directive: &str) -> bool {
+    matches!(
+        directive.to_lowercase().as_str(),
+        "off" | "error" | "warn" | "info" | "debug" | "trace"
+    )
+}
+
+/// A bounded, time-retained buffer of recent log records, queryable without
+/// scraping the RillRate stream.
+struct LogBuffer {
+    records: Mutex<VecDeque<Arc<LogRecord>>>,
+    retention: Duration,
+}
+
+impl LogBuffer {
+    fn new(retention: Duration) -> Arc<Self> {
+        let buffer = Arc::new(Self {
+            records: Mutex::new(VecDeque::new()),
+            retention,
+        });
+        let sweeper = Arc::clone(&buffer);
+        thread::spawn(move || loop {
+            thread::sleep(LOG_SWEEP_INTERVAL);
+            sweeper.evict_expired();
+        });
+        buffer
+    }
+
+    fn push(&self, record: LogRecord) {
+        let mut records = self.records.lock().expect("log buffer poisoned");
+        records.push_back(Arc::new(record));
+        // Time-based eviction only runs every LOG_SWEEP_INTERVAL, so a chatty log
+        // source could otherwise grow the buffer unbounded between sweeps; enforce a
+        // hard cap on every push so it's actually bounded, not just eventually trimmed.
+        while records.len() > LOG_BUFFER_CAPACITY {
+            records.pop_front();
+        }
+    }
+
+    fn evict_expired(&self) {
+        if let Some(cutoff) = SystemTime::now().checked_sub(self.retention) {
+            let mut records = self.records.lock().expect("log buffer poisoned");
+            records.retain(|record| record.timestamp >= cutoff);
+        }
+    }
+
+    /// Walks the buffer newest-first, returning up to `filter.limit` matches.
+    fn query(&self, filter: &LogQuery) -> Vec<Arc<LogRecord>> {
+        let limit = filter.limit.unwrap_or(DEFAULT_QUERY_LIMIT);
+        let records = self.records.lock().expect("log buffer poisoned");
+        records
+            .iter()
+            .rev()
+            .filter(|record| filter.matches(record))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MetricKind {
+    Gauge,
+    Counter,
+    Log,
+}
+
+impl MetricKind {
+    fn of(value: &Value) -> Self {
+        match value {
+            Value::Gauge(_) => MetricKind::Gauge,
+            Value::Counter(_) | Value::Derive(_) | Value::Absolute(_) => MetricKind::Counter,
+        }
+    }
+}
+
+/// A tracer for a single path, typed according to the kind of collectd value it carries.
+enum Metric {
+    Gauge(GaugeTracer),
+    Counter(CounterTracer),
+    Log(LogTracer),
+}
+
+impl Metric {
+    fn new(path: Path, value: &Value) -> Self {
+        match MetricKind::of(value) {
+            MetricKind::Gauge => Metric::Gauge(GaugeTracer::new(path, true)),
+            MetricKind::Counter => Metric::Counter(CounterTracer::new(path, true)),
+            MetricKind::Log => Metric::Log(LogTracer::new(path, true)),
+        }
+    }
+
+    fn kind(&self) -> MetricKind {
+        match self {
+            Metric::Gauge(_) => MetricKind::Gauge,
+            Metric::Counter(_) => MetricKind::Counter,
+            Metric::Log(_) => MetricKind::Log,
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        match self {
+            Metric::Gauge(tracer) => tracer.is_active(),
+            Metric::Counter(tracer) => tracer.is_active(),
+            Metric::Log(tracer) => tracer.is_active(),
+        }
+    }
+
+    // collectd's Counter/Absolute values are u64 and routinely exceed i64::MAX (e.g.
+    // cumulative byte counters), while RillRate's CounterTracer::log only accepts i64.
+    // Saturate rather than truncate so an oversized sample reads as "pegged at max"
+    // instead of silently wrapping to a negative number.
+    //
+    // Note: this assumes CounterTracer::log treats its argument as an absolute level
+    // (matching collectd's own Counter/Absolute semantics), not a delta to accumulate;
+    // we have no way to verify that against the crate source in this environment.
+    fn log(&self, value: &Value, ts: Option<SystemTime>) {
+        match (self, value) {
+            (Metric::Gauge(tracer), Value::Gauge(v)) => tracer.log(*v, ts),
+            (Metric::Counter(tracer), Value::Counter(v)) => tracer.log(saturating_i64(*v), ts),
+            (Metric::Counter(tracer), Value::Derive(v)) => tracer.log(*v, ts),
+            (Metric::Counter(tracer), Value::Absolute(v)) => tracer.log(saturating_i64(*v), ts),
+            (Metric::Log(tracer), value) => tracer.log(value.to_string(), ts),
+            _ => {
+                log::warn!("Value kind doesn't match the tracer kind, dropping sample");
+            }
+        }
+    }
+}
+
+fn hash_value(value: &Value) -> u64 {
+    xxhash_rust::xxh3::xxh3_64(value.to_string().as_bytes())
+}
+
+/// Convert a collectd `u64` counter/absolute sample to the `i64` RillRate's
+/// `CounterTracer::log` expects, saturating at `i64::MAX` instead of wrapping negative
+/// for values collectd allows but `i64` can't represent.
+fn saturating_i64(value: u64) -> i64 {
+    match i64::try_from(value) {
+        Ok(v) => v,
+        Err(_) => {
+            log::warn!(
+                "teleport-collectd: counter value {} exceeds i64::MAX, saturating",
+                value
+            );
+            i64::MAX
+        }
+    }
+}
+
+/// Converts a collectd `cdtime_t` (whole seconds in the upper 34 bits,
+/// `1 / 2^30` second fractions in the lower 30 bits) into a `Duration` relative to
+/// whatever epoch the `cdtime_t` itself is relative to -- the unix epoch for an
+/// absolute sample timestamp, or the zero instant for an interval/duration.
+fn cdtime_to_duration(time: CdTime) -> Duration {
+    let raw = time.0;
+    let hi = raw >> 30;
+    let lo = raw & 0x3fff_ffff;
+    let nanos = (lo as u128 * 1_000_000_000 / (1u128 << 30)) as u64;
+    Duration::from_secs(hi) + Duration::from_nanos(nanos)
+}
+
+/// Converts a collectd `cdtime_t` *absolute sample timestamp* into a `SystemTime`.
+/// Do not use this for `ValueList::interval`, which is a duration, not an instant --
+/// use [`cdtime_to_duration`] for that instead.
+fn cdtime_to_system_time(time: CdTime) -> SystemTime {
+    SystemTime::UNIX_EPOCH + cdtime_to_duration(time)
+}
+
+/// A tracked path's tracer, plus the xxh3 hash of the last value actually forwarded to it.
+struct TracedMetric {
+    metric: Metric,
+    last_hash: Mutex<Option<u64>>,
+}
+
+impl TracedMetric {
+    fn new(path: Path, value: &Value) -> Self {
+        Self {
+            metric: Metric::new(path, value),
+            last_hash: Mutex::new(None),
+        }
+    }
+
+    fn kind(&self) -> MetricKind {
+        self.metric.kind()
+    }
+
+    fn is_active(&self) -> bool {
+        self.metric.is_active()
+    }
+
+    /// Forwards `value` unless `dedup` is set and it hashes the same as the
+    /// last value forwarded for this path. Returns `true` if it was forwarded.
+    fn log(&self, value: &Value, ts: Option<SystemTime>, dedup: bool) -> bool {
+        if dedup {
+            let hash = hash_value(value);
+            let mut last_hash = self.last_hash.lock().expect("metric hash poisoned");
+            if *last_hash == Some(hash) {
+                return false;
+            }
+            *last_hash = Some(hash);
+        }
+        self.metric.log(value, ts);
+        true
+    }
+}
 
 struct TeleportColelctd {
-    tracers: RwLock<Pathfinder<LogTracer>>,
+    tracers: RwLock<Pathfinder<TracedMetric>>,
     loggers: RwLock<HashMap<LogLevel, LogTracer>>,
+    log_buffer: Arc<LogBuffer>,
+    filter: Filter,
+    suppressed_samples: AtomicU64,
+    settings: Settings,
 }
 
 impl TeleportColelctd {
-    fn new() -> Self {
+    fn new(settings: Settings) -> Self {
         let mut loggers = HashMap::new();
         for level in LogLevel::iter() {
             let path = Path::from(vec![EntryId::from("log"), EntryId::from(level.as_ref())]);
             let logger = LogTracer::new(path, false);
             loggers.insert(level, logger);
         }
+        let filter = build_filter(&settings);
+        let retention = settings
+            .log_retention_secs
+            .map(Duration::from_secs)
+            .unwrap_or(LOG_RETENTION);
         Self {
             tracers: RwLock::new(Pathfinder::new()),
             loggers: RwLock::new(loggers),
+            log_buffer: LogBuffer::new(retention),
+            filter,
+            suppressed_samples: AtomicU64::new(0),
+            settings,
         }
     }
+
+    /// Queries the recent in-memory log history without touching RillRate.
+    #[allow(dead_code)]
+    fn query_logs(&self, filter: &LogQuery) -> Vec<Arc<LogRecord>> {
+        self.log_buffer.query(filter)
+    }
+
+    /// Number of samples dropped so far because `dedup` found them unchanged.
+    #[allow(dead_code)]
+    fn suppressed_samples(&self) -> u64 {
+        self.suppressed_samples.load(Ordering::Relaxed)
+    }
+
+    /// Records an event in the in-memory log buffer, independent of whether it's
+    /// also forwarded to RillRate as a log tracer entry.
+    fn record_event(&self, level: LogLevel, message: String) {
+        self.log_buffer.push(LogRecord {
+            level,
+            target: Self::name().to_string(),
+            message,
+            timestamp: SystemTime::now(),
+        });
+    }
 }
 
 impl PluginManager for TeleportColelctd {
@@ -51,17 +518,37 @@ impl PluginManager for TeleportColelctd {
             .prefix_plugin::<Self>()
             .filter_level(LevelFilter::Info)
             .try_init()?;
-        // TODO: But use `from_config` instead
-        // TODO: And prepare that config
+        // Config is parsed in `plugins`, which collectd calls before `initialize`.
+        let settings = SETTINGS.lock()?.clone();
+        // `plugins` built the log filter before this logger existed, so its directive
+        // warnings were dropped; repeat the check now that they reach collectd's log.
+        if let Some(directives) = settings
+            .log_level
+            .clone()
+            .or_else(|| std::env::var("COLLECTD_LOG").ok())
+            .or_else(|| std::env::var("RUST_LOG").ok())
+        {
+            warn_on_unsupported_directives(&directives);
+        }
+        if let Some(node) = &settings.node {
+            // `RillRate` exposes no constructor that takes the node address directly,
+            // only `from_env`, which it documents as reading `RILLRATE_NODE`. Set it
+            // here so the parsed `Node` config wins over whatever was already in the
+            // environment. If a `rillrate` upgrade renames that variable, `Node` will
+            // stop taking effect silently -- check its changelog if this stops working.
+            std::env::set_var("RILLRATE_NODE", node);
+        }
         let rillrate = RillRate::from_env("teleport-collectd")?;
         *RILLRATE.lock()? = Some(rillrate);
         Ok(())
     }
 
     fn plugins(
-        _config: Option<&[ConfigItem<'_>]>,
+        config: Option<&[ConfigItem<'_>]>,
     ) -> Result<PluginRegistration, Box<dyn error::Error>> {
-        let plugin = Self::new();
+        let settings = Settings::parse(config);
+        *SETTINGS.lock()? = settings.clone();
+        let plugin = Self::new(settings);
         Ok(PluginRegistration::Single(Box::new(plugin)))
     }
 
@@ -72,30 +559,38 @@ impl PluginManager for TeleportColelctd {
 }
 
 impl TeleportColelctd {
-    fn write_value(&self, path: Path, _ts: &str, report: &ValueReport) -> Result<(), Error> {
+    fn write_value(&self, path: Path, ts: SystemTime, report: &ValueReport) -> Result<(), Error> {
         // Try to find an existent tracer
         {
             let tracers = self.tracers.read().map_err(|e| Error::msg(e.to_string()))?;
-            let tracer = tracers.find(&path).and_then(Record::get_link);
-            if let Some(tracer) = tracer {
-                if tracer.is_active() {
-                    let value = report.value.to_string();
-                    // TODO: Convert ts to `SystemTime`
-                    tracer.log(value, None);
+            let metric = tracers.find(&path).and_then(Record::get_link);
+            if let Some(metric) = metric {
+                if metric.kind() == MetricKind::of(&report.value) {
+                    if metric.is_active() {
+                        let forwarded = metric.log(&report.value, Some(ts), self.settings.dedup);
+                        if !forwarded {
+                            self.suppressed_samples.fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                    return Ok(());
                 }
-                return Ok(());
+                let message = format!("Value kind changed for {}, re-creating the tracer", path);
+                log::warn!("{}", message);
+                self.record_event(LogLevel::Warning, message);
             }
         }
-        // Creating a new tracer
+        // Creating a new tracer (or replacing one of a mismatched kind)
         {
-            log::info!("Creating a new tracer for: {}", path);
+            let message = format!("Creating a new tracer for: {}", path);
+            log::info!("{}", message);
+            self.record_event(LogLevel::Info, message);
             let mut tracers = self
                 .tracers
                 .write()
                 .map_err(|e| Error::msg(e.to_string()))?;
-            let tracer = LogTracer::new(path.clone(), true);
+            let metric = TracedMetric::new(path.clone(), &report.value);
             // It can't be active here, since it hadn't existed in the tracer.
-            tracers.dig(path).set_link(tracer);
+            tracers.dig(path).set_link(metric);
         }
         Ok(())
     }
@@ -107,17 +602,31 @@ impl Plugin for TeleportColelctd {
     }
 
     fn log(&self, lvl: LogLevel, msg: &str) -> Result<(), Box<dyn error::Error>> {
+        self.record_event(lvl.clone(), msg.to_string());
+        if !self.filter.matches(
+            &log::Record::builder()
+                .level(to_log_level(&lvl))
+                .target(Self::name())
+                .args(format_args!("{}", msg))
+                .build(),
+        ) {
+            return Ok(());
+        }
         let loggers = self.loggers.read().map_err(|e| Error::msg(e.to_string()))?;
         // TODO: Replace unwrap to err
         let tracer = loggers.get(&lvl).unwrap();
         if tracer.is_active() {
-            tracer.log(msg.to_string(), None);
+            tracer.log(msg.to_string(), Some(SystemTime::now()));
         }
         Ok(())
     }
 
     fn write_values(&self, list: ValueList<'_>) -> Result<(), Box<dyn error::Error>> {
-        let host = EntryId::from(list.host);
+        if !self.settings.is_allowed(list.plugin) {
+            return Ok(());
+        }
+        let host_name = self.settings.hostname.as_deref().unwrap_or(list.host);
+        let host = EntryId::from(host_name);
         let plugin = EntryId::from(list.plugin);
         let plugin_instance = list.plugin_instance.map(EntryId::from);
         let typ = EntryId::from(list.type_);
@@ -136,15 +645,21 @@ impl Plugin for TeleportColelctd {
             entries.push(value);
         }
         let basic_path = Path::from(entries);
-        let ts = list.time.to_string();
+        let ts = cdtime_to_system_time(list.time);
+        log::debug!(
+            "Received values for {} at {:?} (interval {:?})",
+            basic_path,
+            ts,
+            cdtime_to_duration(list.interval)
+        );
         let err;
         if list.values.len() == 1 {
             let report = list.values.get(0).unwrap();
-            err = self.write_value(basic_path, &ts, report).err();
+            err = self.write_value(basic_path, ts, report).err();
         } else {
             err = list.values.par_iter().find_map_last(move |report| {
                 let path = basic_path.concat(report.name);
-                self.write_value(path, &ts, report).err()
+                self.write_value(path, ts, report).err()
             });
         }
         if let Some(err) = err {